@@ -0,0 +1,386 @@
+//! A format-string engine modelled on the classic `Item` list.
+//!
+//! A `%`-format string such as `"%Y-%m-%dT%H:%M:%S"` is lexed once into a
+//! `Vec<Item>` by `StrftimeItems`, and that same list drives both
+//! formatting (`format_with_items`) and parsing (`parse_with_items`). The
+//! ISO 8601 and RFC parsers are the obvious callers to route through here.
+
+use local::{LocalDate, LocalTime, LocalDateTime, Month, Weekday};
+
+/// How a numeric field is padded out to its minimum width.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Pad {
+    None,
+    Zero,
+    Space,
+}
+
+/// A numeric field of a datetime, together with its natural width.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Numeric {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Ordinal,
+    WeekNumber,
+}
+
+/// A named or otherwise non-numeric field.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Fixed {
+    ShortMonthName,
+    LongWeekdayName,
+    TimezoneOffset,
+
+    /// The whole RFC 2822 layout, e.g. `Tue, 20 Jan 2015 17:35:20 -0800`.
+    RFC2822,
+
+    /// The whole RFC 3339 layout, e.g. `2015-01-20T17:35:20-08:00`.
+    RFC3339,
+}
+
+/// A single piece of a format string.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Item<'a> {
+    Literal(&'a str),
+    Numeric(Numeric, Pad),
+    Fixed(Fixed),
+}
+
+/// Lexes a `%`-format string into a list of `Item`s.
+pub struct StrftimeItems<'a> {
+    remainder: &'a str,
+}
+
+impl<'a> StrftimeItems<'a> {
+    /// Begins lexing the given format string.
+    pub fn new(format:&'a str) -> StrftimeItems<'a> {
+        StrftimeItems { remainder: format }
+    }
+
+    /// Consumes the whole format string, producing its `Item` list. A
+    /// trailing `%` with nothing after it is emitted as a literal `%`.
+    pub fn parse(mut self) -> Vec<Item<'a>> {
+        let mut items = Vec::new();
+        while ! self.remainder.is_empty() {
+            match self.remainder.find('%') {
+                None => {
+                    items.push(Item::Literal(self.remainder));
+                    self.remainder = "";
+                },
+                Some(0) => {
+                    // The character after `%` may be multi-byte, so advance
+                    // by whole chars rather than a fixed two bytes.
+                    match self.remainder[1..].chars().next() {
+                        None => {
+                            items.push(Item::Literal("%"));
+                            self.remainder = "";
+                        },
+                        Some(spec) => {
+                            let end = 1 + spec.len_utf8();
+                            items.push(item_for_spec(spec, &self.remainder[..end]));
+                            self.remainder = &self.remainder[end..];
+                        },
+                    }
+                },
+                Some(index) => {
+                    items.push(Item::Literal(&self.remainder[..index]));
+                    self.remainder = &self.remainder[index..];
+                },
+            }
+        }
+        items
+    }
+}
+
+/// Maps a single conversion character onto its `Item`. Unknown specifiers
+/// pass through verbatim as the literal `%` followed by the character
+/// (`raw` is the whole `%x` slice, longer than two bytes when `x` is a
+/// multi-byte character), matching the common strftime
+/// fallback.
+fn item_for_spec(spec:char, raw:&str) -> Item {
+    match spec {
+        'Y' => Item::Numeric(Numeric::Year,       Pad::Zero),
+        'm' => Item::Numeric(Numeric::Month,      Pad::Zero),
+        'd' => Item::Numeric(Numeric::Day,        Pad::Zero),
+        'e' => Item::Numeric(Numeric::Day,        Pad::Space),
+        'H' => Item::Numeric(Numeric::Hour,       Pad::Zero),
+        'M' => Item::Numeric(Numeric::Minute,     Pad::Zero),
+        'S' => Item::Numeric(Numeric::Second,     Pad::Zero),
+        'j' => Item::Numeric(Numeric::Ordinal,    Pad::Zero),
+        'U' => Item::Numeric(Numeric::WeekNumber, Pad::Zero),
+        'b' => Item::Fixed(Fixed::ShortMonthName),
+        'A' => Item::Fixed(Fixed::LongWeekdayName),
+        'z' => Item::Fixed(Fixed::TimezoneOffset),
+        'c' => Item::Fixed(Fixed::RFC2822),
+        '+' => Item::Fixed(Fixed::RFC3339),
+        '%' => Item::Literal("%"),
+        _   => Item::Literal(raw),
+    }
+}
+
+/// Formats a `LocalDateTime` according to an `Item` list.
+pub fn format_with_items(when:&LocalDateTime, items:&[Item]) -> String {
+    let date = when.date();
+    let time = when.time();
+    let mut out = String::new();
+
+    for item in items {
+        match *item {
+            Item::Literal(text) => out.push_str(text),
+            Item::Numeric(field, pad) => {
+                let (value, width) = numeric_value(&date, &time, field);
+                out.push_str(&pad_number(value, width, pad));
+            },
+            Item::Fixed(Fixed::ShortMonthName) =>
+                out.push_str(short_month_name(date.month())),
+            Item::Fixed(Fixed::LongWeekdayName) =>
+                out.push_str(long_weekday_name(date.weekday())),
+            Item::Fixed(Fixed::TimezoneOffset) =>
+                out.push_str("+0000"),
+            Item::Fixed(Fixed::RFC2822) =>
+                out.push_str(&format_rfc2822(&date, &time)),
+            Item::Fixed(Fixed::RFC3339) =>
+                out.push_str(&format_rfc3339(&date, &time)),
+        }
+    }
+    out
+}
+
+/// The optional fields filled in while parsing, before they are resolved
+/// into a `LocalDate` / `LocalTime`.
+#[derive(PartialEq, Eq, Debug, Default, Copy, Clone)]
+pub struct Parsed {
+    pub year:   Option<i64>,
+    pub month:  Option<i8>,
+    pub day:    Option<i8>,
+    pub hour:   Option<i8>,
+    pub minute: Option<i8>,
+    pub second: Option<i8>,
+}
+
+impl Parsed {
+    /// Resolves the collected fields into a date, defaulting the day and
+    /// month to the first of January when they are absent.
+    pub fn to_date(&self) -> Option<LocalDate> {
+        match self.year {
+            Some(year) => LocalDate::new(
+                year,
+                Month::from_one(self.month.unwrap_or(1)),
+                self.day.unwrap_or(1),
+                ),
+            None => None,
+        }
+    }
+
+    /// Resolves the collected fields into a time, defaulting absent
+    /// components to zero.
+    pub fn to_time(&self) -> Option<LocalTime> {
+        LocalTime::hms(
+            self.hour.unwrap_or(0),
+            self.minute.unwrap_or(0),
+            self.second.unwrap_or(0),
+            )
+    }
+}
+
+/// Fills a `Parsed` from the given string according to an `Item` list.
+///
+/// Literals must match verbatim, numeric fields consume a leading run of
+/// digits (after optional padding), and a mismatch anywhere yields `None`.
+pub fn parse_with_items(parsed:&mut Parsed, mut s:&str, items:&[Item]) -> Option<()> {
+    for item in items {
+        match *item {
+            Item::Literal(text) => {
+                if s.starts_with(text) {
+                    s = &s[text.len()..];
+                } else {
+                    return None;
+                }
+            },
+            Item::Numeric(field, _) => {
+                s = s.trim_left_matches(' ');
+                let digits = s.chars().take_while(|c| c.is_digit(10)).count();
+                if digits == 0 { return None; }
+                let (number, rest) = s.split_at(digits);
+                store_numeric(parsed, field, number);
+                s = rest;
+            },
+            Item::Fixed(Fixed::ShortMonthName) => {
+                // Split after the third char without cutting a UTF-8 byte.
+                let end = match s.char_indices().nth(2) {
+                    Some((index, c)) => index + c.len_utf8(),
+                    None             => return None,
+                };
+                let (name, rest) = s.split_at(end);
+                match month_from_short_name(name) {
+                    Some(month) => parsed.month = Some(month),
+                    None        => return None,
+                }
+                s = rest;
+            },
+            // The remaining fixed items are whole-format shortcuts or
+            // carry no field worth storing on their own.
+            Item::Fixed(_) => return None,
+        }
+    }
+
+    if s.is_empty() { Some(()) } else { None }
+}
+
+fn store_numeric(parsed:&mut Parsed, field:Numeric, number:&str) {
+    match field {
+        Numeric::Year       => parsed.year   = number.parse().ok(),
+        Numeric::Month      => parsed.month  = number.parse().ok(),
+        Numeric::Day        => parsed.day    = number.parse().ok(),
+        Numeric::Hour       => parsed.hour   = number.parse().ok(),
+        Numeric::Minute     => parsed.minute = number.parse().ok(),
+        Numeric::Second     => parsed.second = number.parse().ok(),
+        // Ordinal and week numbers are not resolved back into a date here.
+        Numeric::Ordinal | Numeric::WeekNumber => {},
+    }
+}
+
+fn numeric_value(date:&LocalDate, time:&LocalTime, field:Numeric) -> (i64, usize) {
+    match field {
+        Numeric::Year       => (date.year() as i64, 4),
+        Numeric::Month      => (month_to_one(date.month()) as i64, 2),
+        Numeric::Day        => (date.day() as i64, 2),
+        Numeric::Hour       => (time.hour() as i64, 2),
+        Numeric::Minute     => (time.minute() as i64, 2),
+        Numeric::Second     => (time.second() as i64, 2),
+        Numeric::Ordinal    => (date.yearday() as i64, 3),
+        Numeric::WeekNumber => (date.week_of_year() as i64, 2),
+    }
+}
+
+fn pad_number(value:i64, width:usize, pad:Pad) -> String {
+    let body = format!("{}", value.abs());
+    let padded = match pad {
+        Pad::None  => body,
+        Pad::Zero  => format!("{:0>1$}", body, width),
+        Pad::Space => format!("{:>1$}", body, width),
+    };
+    if value < 0 { format!("-{}", padded) } else { padded }
+}
+
+fn format_rfc2822(date:&LocalDate, time:&LocalTime) -> String {
+    format!("{}, {:02} {} {:04} {:02}:{:02}:{:02} +0000",
+        short_weekday_name(date.weekday()),
+        date.day(), short_month_name(date.month()), date.year(),
+        time.hour(), time.minute(), time.second())
+}
+
+fn format_rfc3339(date:&LocalDate, time:&LocalTime) -> String {
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}+00:00",
+        date.year(), month_to_one(date.month()), date.day(),
+        time.hour(), time.minute(), time.second())
+}
+
+fn month_to_one(month:Month) -> i8 {
+    match month {
+        Month::January =>  1, Month::February =>  2, Month::March     =>  3,
+        Month::April   =>  4, Month::May      =>  5, Month::June      =>  6,
+        Month::July    =>  7, Month::August   =>  8, Month::September =>  9,
+        Month::October => 10, Month::November => 11, Month::December  => 12,
+    }
+}
+
+fn short_month_name(month:Month) -> &'static str {
+    match month {
+        Month::January => "Jan", Month::February => "Feb", Month::March     => "Mar",
+        Month::April   => "Apr", Month::May      => "May", Month::June      => "Jun",
+        Month::July    => "Jul", Month::August   => "Aug", Month::September => "Sep",
+        Month::October => "Oct", Month::November => "Nov", Month::December  => "Dec",
+    }
+}
+
+fn month_from_short_name(name:&str) -> Option<i8> {
+    Some(match name {
+        "Jan" =>  1, "Feb" =>  2, "Mar" =>  3, "Apr" =>  4,
+        "May" =>  5, "Jun" =>  6, "Jul" =>  7, "Aug" =>  8,
+        "Sep" =>  9, "Oct" => 10, "Nov" => 11, "Dec" => 12,
+        _ => return None,
+    })
+}
+
+fn long_weekday_name(weekday:Weekday) -> &'static str {
+    match weekday {
+        Weekday::Monday    => "Monday",    Weekday::Tuesday  => "Tuesday",
+        Weekday::Wednesday => "Wednesday", Weekday::Thursday => "Thursday",
+        Weekday::Friday    => "Friday",    Weekday::Saturday => "Saturday",
+        Weekday::Sunday    => "Sunday",
+    }
+}
+
+fn short_weekday_name(weekday:Weekday) -> &'static str {
+    match weekday {
+        Weekday::Monday    => "Mon", Weekday::Tuesday  => "Tue",
+        Weekday::Wednesday => "Wed", Weekday::Thursday => "Thu",
+        Weekday::Friday    => "Fri", Weekday::Saturday => "Sat",
+        Weekday::Sunday    => "Sun",
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    pub use super::{StrftimeItems, Item, Numeric, Pad};
+    pub use super::{format_with_items, parse_with_items, Parsed};
+    pub use local::{LocalDate, LocalTime, LocalDateTime, Month};
+
+    fn datetime() -> LocalDateTime {
+        let date = LocalDate::new(2015, Month::January, 20).unwrap();
+        let time = LocalTime::hms(17, 35, 20).unwrap();
+        LocalDateTime::from_date_time(date, time)
+    }
+
+    #[test]
+    fn lex() {
+        let items = StrftimeItems::new("%Y-%m-%d").parse();
+        assert_eq!(items, vec![
+            Item::Numeric(Numeric::Year,  Pad::Zero),
+            Item::Literal("-"),
+            Item::Numeric(Numeric::Month, Pad::Zero),
+            Item::Literal("-"),
+            Item::Numeric(Numeric::Day,   Pad::Zero),
+        ]);
+    }
+
+    #[test]
+    fn lex_unknown_spec() {
+        let items = StrftimeItems::new("%q").parse();
+        assert_eq!(items, vec![Item::Literal("%q")]);
+    }
+
+    #[test]
+    fn lex_non_ascii_spec() {
+        let items = StrftimeItems::new("%é").parse();
+        assert_eq!(items, vec![Item::Literal("%é")]);
+    }
+
+    #[test]
+    fn format() {
+        let items = StrftimeItems::new("%Y-%m-%dT%H:%M:%S").parse();
+        assert_eq!(format_with_items(&datetime(), &items), "2015-01-20T17:35:20");
+    }
+
+    #[test]
+    fn rfc2822_abbreviates_weekday() {
+        let items = StrftimeItems::new("%c").parse();
+        assert_eq!(format_with_items(&datetime(), &items),
+                   "Tue, 20 Jan 2015 17:35:20 +0000");
+    }
+
+    #[test]
+    fn round_trip() {
+        let items = StrftimeItems::new("%Y-%m-%d").parse();
+        let mut parsed = Parsed::default();
+        let text = format_with_items(&datetime(), &items);
+        assert_eq!(parse_with_items(&mut parsed, &text, &items), Some(()));
+        assert_eq!(parsed.to_date(), LocalDate::new(2015, Month::January, 20));
+    }
+}
@@ -1,107 +1,308 @@
-use local::{LocalDate, LocalTime, LocalDateTime, Month};
+use std::str::FromStr;
+
+use local::{LocalDate, LocalTime, LocalDateTime, Month, Year};
 use zoned::*;
 
 use regex::Regex;
 
+/// Everything that can go wrong while parsing a date, time or datetime.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum ParseError {
+    /// The input stopped before a whole value had been read.
+    TooShort,
+
+    /// The input did not match the expected shape at all.
+    Invalid,
+
+    /// A field parsed but fell outside its allowed range.
+    OutOfRange { field: &'static str },
+
+    /// A value was read, but characters were left unconsumed after it.
+    TrailingInput,
+}
+
+/// Parses a decimal field, reporting an out-of-range failure against the
+/// named field rather than panicking.
+fn parse_field<T: FromStr>(text:&str, field:&'static str) -> Result<T, ParseError> {
+    text.parse().map_err(|_| ParseError::OutOfRange { field: field })
+}
+
+/// Turns the `None` of a range-checked constructor into an out-of-range
+/// failure against the named field.
+fn from_option<T>(value:Option<T>, field:&'static str) -> Result<T, ParseError> {
+    value.ok_or(ParseError::OutOfRange { field: field })
+}
+
 /// Splits Date String, Time String
 ///
 /// for further parsing by `parse_iso_8601_date` and `parse_iso_8601_time`.
-pub fn split_iso_8601(string:&str) -> Option<(&str, &str)> {
+pub fn split_iso_8601(string:&str) -> Result<(&str, &str), ParseError> {
     let split = Regex::new(r"^([^T]*)T?(.*)$").unwrap();
-    if split.is_match(&string) {
-        let caps = split.captures(&string).unwrap();
-        if caps.len() > 1 {
-            return Some( (caps.at(1).unwrap().into(), caps.at(2).unwrap().into()) );
-        }
+    match split.captures(&string) {
+        Some(caps) => Ok((caps.at(1).unwrap(), caps.at(2).unwrap())),
+        None       => Err(ParseError::TooShort),
     }
-    None
 }
 
 /// Parses a ISO 8601 a string into LocalDateTime Object.
-pub fn parse_iso_8601(string:&str) -> Option<LocalDateTime> {
-    let (date_string, time_string) = split_iso_8601(string).unwrap();
-    match (parse_iso_8601_date(&date_string), parse_iso_8601_time(&time_string)) {
-        (Some(date),Some(time)) => return Some(LocalDateTime::from_date_time(date,time)),
-        _ => None
+pub fn parse_iso_8601(string:&str) -> Result<LocalDateTime, ParseError> {
+    let (date_string, time_string) = try!(split_iso_8601(string));
+    let date = try!(parse_iso_8601_date(date_string));
+    let time = try!(parse_iso_8601_time(time_string));
+    Ok(LocalDateTime::from_date_time(date, time))
+}
+
+
+/// Parses an RFC 2822 timestamp — the format found in email and HTTP
+/// headers, such as `"Tue, 20 Jan 2015 17:35:20 -0800"` — into a
+/// `LocalDateTime` and the `TimeZone` named by its trailing offset.
+///
+/// Runs of whitespace are folded and `(...)` comments stripped before
+/// matching, and any malformed field yields `None` rather than a panic.
+///
+/// Used by `ZonedDateTime::parse_rfc_2822()`
+pub fn parse_rfc_2822(string:&str) -> Option<(LocalDateTime, TimeZone)> {
+    let comment = Regex::new(r"\([^)]*\)").unwrap();
+    let space   = Regex::new(r"\s+").unwrap();
+    let stripped = comment.replace_all(&string, " ");
+    let folded   = space.replace_all(&stripped, " ");
+    let trimmed  = folded.trim();
+
+    let exp = Regex::new(r##"(?x) ^
+        (?:[A-Za-z]{3}\ ?,\ ?)?  # optional day-of-week followed by a comma
+        (\d{1,2})\               # day
+        ([A-Za-z]{3})\           # three-letter month abbreviation
+        (\d{4})\                 # year
+        (\d{2}):(\d{2})          # hour and minute
+        (?::(\d{2}))?            # optional second
+        \ (\S+)                  # time zone
+        $"##).unwrap();
+
+    if ! exp.is_match(&trimmed) { return None; }
+    let caps = exp.captures(&trimmed).unwrap();
+
+    let month = match month_from_abbreviation(caps.at(2).unwrap()) {
+        Some(month) => month,
+        None        => return None,
+    };
+
+    let zone = match parse_rfc_2822_zone(caps.at(7).unwrap()) {
+        Some(zone) => zone,
+        None       => return None,
+    };
+
+    let date = LocalDate::new(
+        caps.at(3).unwrap().parse().unwrap(), // year
+        month,                                // month
+        caps.at(1).unwrap().parse().unwrap(), // day
+        );
+    let time = LocalTime::hms(
+        caps.at(4).unwrap().parse().unwrap(),         // hour
+        caps.at(5).unwrap().parse().unwrap(),         // minute
+        caps.at(6).unwrap_or("00").parse().unwrap(),  // second
+        );
+
+    match (date, time) {
+        (Some(date), Some(time)) =>
+            Some((LocalDateTime::from_date_time(date, time), zone)),
+        _ => None,
+    }
+}
+
+impl LocalDate {
+
+    /// Parses an ISO 8601 date, reporting a typed `ParseError` — an
+    /// out-of-range field or trailing input — rather than panicking.
+    pub fn parse(string:&str) -> Result<LocalDate, ParseError> {
+        parse_iso_8601_date(string)
+    }
+}
+
+impl LocalTime {
+
+    /// Parses an ISO 8601 time, reporting a typed `ParseError` — an
+    /// out-of-range field or trailing input — rather than panicking.
+    pub fn parse(string:&str) -> Result<LocalTime, ParseError> {
+        parse_iso_8601_time(string)
+    }
+}
+
+impl ZonedDateTime {
+
+    /// Parses an ISO 8601 datetime with its zone offset, reporting a typed
+    /// `ParseError` rather than panicking.
+    pub fn parse(string:&str) -> Result<ZonedDateTime, ParseError> {
+        let (local, zone) = try!(parse_iso_8601_zoned(string));
+        Ok(zone.to_zoned(local))
+    }
+
+    /// Parses an RFC 2822 timestamp — as found in email and HTTP headers,
+    /// such as `"Tue, 20 Jan 2015 17:35:20 -0800"` — pairing the local time
+    /// with the `TimeZone` named by its trailing offset. A malformed field
+    /// yields `None` rather than a panic.
+    pub fn parse_rfc_2822(string:&str) -> Option<ZonedDateTime> {
+        parse_rfc_2822(string).map(|(local, zone)| zone.to_zoned(local))
     }
 }
 
+/// Maps a three-letter English month abbreviation onto a `Month` via
+/// `Month::from_one`, returning `None` for anything else.
+fn month_from_abbreviation(abbr:&str) -> Option<Month> {
+    let number = match abbr {
+        "Jan" =>  1, "Feb" =>  2, "Mar" =>  3, "Apr" =>  4,
+        "May" =>  5, "Jun" =>  6, "Jul" =>  7, "Aug" =>  8,
+        "Sep" =>  9, "Oct" => 10, "Nov" => 11, "Dec" => 12,
+        _ => return None,
+    };
+    Some(Month::from_one(number))
+}
+
+/// Parses the trailing zone token of an RFC 2822 timestamp: either a
+/// numeric `±HHMM` offset or one of the obsolete named zones. Per the
+/// spec, any other single military letter is treated as `-0000` (UTC).
+fn parse_rfc_2822_zone(zone:&str) -> Option<TimeZone> {
+    let numeric = Regex::new(r"^([+-]\d{2})(\d{2})$").unwrap();
+    if numeric.is_match(&zone) {
+        let caps = numeric.captures(&zone).unwrap();
+        return Some(TimeZone::of_hours_and_minutes(
+            caps.at(1).unwrap().trim_matches('+').parse().unwrap(),
+            caps.at(2).unwrap().parse().unwrap(),
+            ));
+    }
+
+    let (hours, minutes) = match zone {
+        "UT" | "GMT" | "Z" => (0, 0),
+        "EST" => (-5, 0), "EDT" => (-4, 0),
+        "CST" => (-6, 0), "CDT" => (-5, 0),
+        "MST" => (-7, 0), "MDT" => (-6, 0),
+        "PST" => (-8, 0), "PDT" => (-7, 0),
+        _ => {
+            // Any other single military letter means "treat unknown as -0000".
+            if zone.len() == 1 && zone.chars().all(|c| c.is_alphabetic()) {
+                (0, 0)
+            } else {
+                return None;
+            }
+        }
+    };
+    Some(TimeZone::of_hours_and_minutes(hours, minutes))
+}
 
 /// Parses ISO 8601 Date a string into a LocalDate Object.
 ///
 /// Used by `LocalDate::parse()`
-pub fn parse_iso_8601_date(string:&str) -> Option<LocalDate> {
+pub fn parse_iso_8601_date(string:&str) -> Result<LocalDate, ParseError> {
     let week = Regex::new(r##"(?x)^
         (\d{4})   # year
         -W(\d{2}) # number of week
         -(\d{1})  # day in week (1..7)//}
-        $"##).unwrap();
+        (.*)$"##).unwrap();
     let ymd  = Regex::new(r##"(?x)^
         (\d{4})   # year
         -?(\d{2}) # month
         -?(\d{2}) # day
-        $"##).unwrap();
+        (.*)$"##).unwrap();
+    let ord  = Regex::new(r##"(?x)^
+        (\d{4})   # year
+        -?(\d{3}) # day of the year (1..366)
+        (.*)$"##).unwrap();
+
+    if let Some(caps) = ymd.captures(string) {
+        try!(reject_trailing(caps.at(4).unwrap()));
+        let month = try!(parse_field::<i8>(caps.at(2).unwrap(), "month"));
+        if month < 1 || month > 12 {
+            return Err(ParseError::OutOfRange { field: "month" });
+        }
+        from_option(LocalDate::new(
+            try!(parse_field(caps.at(1).unwrap(), "year")), // year
+            Month::from_one(month),                         // month
+            try!(parse_field(caps.at(3).unwrap(), "day")),  // day
+            ), "day")
+    }
+    else if let Some(caps) = week.captures(string) {
+        try!(reject_trailing(caps.at(4).unwrap()));
+        from_option(LocalDate::from_weekday(
+            try!(parse_field(caps.at(1).unwrap(), "year")),    // year
+            try!(parse_field(caps.at(2).unwrap(), "week")),    // week
+            try!(parse_field(caps.at(3).unwrap(), "weekday")), // weekday
+            ), "weekday")
+    }
+    else if let Some(caps) = ord.captures(string) {
+        try!(reject_trailing(caps.at(3).unwrap()));
+        from_option(date_from_ordinal(
+            try!(parse_field(caps.at(1).unwrap(), "year")),    // year
+            try!(parse_field(caps.at(2).unwrap(), "ordinal")), // day of the year
+            ), "ordinal")
+    }
+    else { Err(ParseError::Invalid) }
+}
+
+/// Fails with `TrailingInput` when a parser’s anchored match left any
+/// characters unconsumed.
+fn reject_trailing(rest:&str) -> Result<(), ParseError> {
+    if rest.is_empty() { Ok(()) } else { Err(ParseError::TrailingInput) }
+}
+
+/// Builds a `LocalDate` from a year and a one-based day-of-year by
+/// walking the months. The ordinal must lie in `1..=365` (or `1..=366`
+/// in a leap year); anything outside that range yields `None`.
+fn date_from_ordinal(year:i64, ordinal:i16) -> Option<LocalDate> {
+    let leap = Year(year).is_leap_year();
+    let days = [31, if leap { 29 } else { 28 }, 31, 30, 31, 30,
+                31, 31, 30, 31, 30, 31];
 
-    if ymd.is_match(&string) {
-        ymd.captures(string).map(|caps|
-        LocalDate::new(
-            caps.at(1).unwrap().parse().unwrap(), // year
-            Month::from_one(caps.at(2).unwrap().parse().unwrap()), // month
-            caps.at(3).unwrap().parse().unwrap(), // day
-            ).unwrap())
+    if ordinal < 1 || ordinal > if leap { 366 } else { 365 } {
+        return None;
     }
-    else if week.is_match(&string) {
-        week.captures(string).map(|caps|
-        LocalDate::from_weekday(
-            caps.at(1).unwrap().parse().unwrap(), // year
-            caps.at(2).unwrap().parse().unwrap(), // week
-            caps.at(3).unwrap().parse().unwrap()  // weekday
-            ).unwrap())
+
+    let mut remaining = ordinal;
+    let mut month = 0;
+    while remaining > days[month] {
+        remaining -= days[month];
+        month += 1;
     }
-    else { None }
+
+    LocalDate::new(year, Month::from_one(month as i8 + 1), remaining as i8)
 }
 
 /// Parses ISO 8601 a string into a ZonedDateTime Object.
 ///
 /// Used by `ZonedDateTime::parse()`
-pub fn parse_iso_8601_zoned(string:&str) -> Option<(LocalDateTime, TimeZone)> {
-    let (date_string, time_string) = split_iso_8601(string).unwrap();
-    match (parse_iso_8601_date(&date_string),parse_iso_8601_tuple(&time_string)){
-        (Some(date), Some((hour, minute, second, millisecond, zh, zm, z)) ) => {
-            if let Some(time) = LocalTime::hms_ms(hour, minute, second, millisecond as i16){
-                let time_zone = if z == "Z" {
-                    TimeZone::UTC
-                } else {
-                    TimeZone::of_hours_and_minutes(zh,zm)
-                };
-
-                Some(( LocalDateTime::from_date_time(date,time), time_zone))
-            } else {None}
-        },
-        (Some(date), None) => {
-            if let Some(time) = LocalTime::hms(0,0,0){
-                Some(( LocalDateTime::from_date_time(date,time), TimeZone::UTC))
-            } else {None}
-        }
-        _ => None
+pub fn parse_iso_8601_zoned(string:&str) -> Result<(LocalDateTime, TimeZone), ParseError> {
+    let (date_string, time_string) = try!(split_iso_8601(string));
+    let date = try!(parse_iso_8601_date(date_string));
+
+    if time_string.is_empty() {
+        let time = try!(from_option(LocalTime::hms(0, 0, 0), "time"));
+        return Ok((LocalDateTime::from_date_time(date, time), TimeZone::UTC));
     }
+
+    let (hour, minute, second, millisecond, zh, zm, z) = try!(parse_iso_8601_tuple(time_string));
+    let time = try!(from_option(
+        LocalTime::hms_ms(hour, minute, second, millisecond as i16), "time"));
+
+    let time_zone = if z == "Z" {
+        TimeZone::UTC
+    } else {
+        TimeZone::of_hours_and_minutes(zh, zm)
+    };
+
+    Ok((LocalDateTime::from_date_time(date, time), time_zone))
 }
 
 /// Parses ISO 8601 a string into a LocalTime Object.
 ///
 /// Used by `LocalTime::parse()`
-pub fn parse_iso_8601_time(string:&str) -> Option<LocalTime> {
+pub fn parse_iso_8601_time(string:&str) -> Result<LocalTime, ParseError> {
     if string.is_empty() {
-        return Some(LocalTime::hms(0,0,0).unwrap());
+        return from_option(LocalTime::hms(0, 0, 0), "time");
     }
-    if let Some((hour, minute, second, millisecond, _zh, _zm, _z)) = parse_iso_8601_tuple(string){
-        return LocalTime::hms_ms(hour, minute, second, millisecond as i16);
-    }
-    None
+    let (hour, minute, second, millisecond, _zh, _zm, _z) = try!(parse_iso_8601_tuple(string));
+    from_option(LocalTime::hms_ms(hour, minute, second, millisecond as i16), "time")
 }
 
 // implementation detail
-fn parse_iso_8601_tuple(string:&str) -> Option<(i8,i8,i8,i32,i8,i8,&str)> {
+fn parse_iso_8601_tuple(string:&str) -> Result<(i8,i8,i8,i32,i8,i8,&str), ParseError> {
     let exp = Regex::new(r##"(?x) ^
         (\d{2}) :?     # hour
         (\d{2})? :?    # minute
@@ -117,44 +318,94 @@ fn parse_iso_8601_tuple(string:&str) -> Option<(i8,i8,i8,i32,i8,i8,&str)> {
             ([+-]\d\d)? :?  # hour and
             (\d\d)?         # minute,
         )?
+        (.*)                # anything left over
     $"##).ok().expect("Regex Broken");
 
-    if exp.is_match(&string) {
-        let tup = exp.captures(string).map(|caps|
-               (
-                caps.at(1).unwrap_or("00").parse::<i8>().unwrap(), // HH
-                caps.at(2).unwrap_or("00").parse::<i8>().unwrap(), // MM
-                caps.at(3).unwrap_or("00").parse::<i8>().unwrap(), // SS
-                caps.at(4).unwrap_or("000").parse::<i32>().unwrap(), // MS
-                caps.at(6).unwrap_or("+00").trim_matches('+').parse::<i8>().unwrap(), // ZH
-                caps.at(7).unwrap_or("00").parse::<i8>().unwrap(), // ZM
-                caps.at(5).unwrap_or("_"), // "Z"
-                )).unwrap();
+    let caps = match exp.captures(string) {
+        Some(caps) => caps,
+        None       => return Err(ParseError::Invalid),
+    };
+
+    try!(reject_trailing(caps.at(8).unwrap_or("")));
 
-        //TODO: check this with the rfc3339 standard
-        //if tup.3 > 0 && &format!("{}", tup.3).len() %3 != 0{ return None}
-        return Some(tup);
+    // Scale the captured fraction to exactly three digits so that
+    // `.5` yields 500ms rather than 5ms, matching RFC 3339 round-trips.
+    let millisecond = match caps.at(4) {
+        Some(fraction) => {
+            let mut digits = fraction.to_string();
+            while digits.len() < 3 { digits.push('0'); }
+            digits.truncate(3);
+            try!(parse_field::<i32>(&digits, "millisecond"))
+        },
+        None => 0,
+    };
+
+    let zone_hours:   i8 = try!(parse_field(caps.at(6).unwrap_or("+00").trim_matches('+'), "offset"));
+    let zone_minutes: i8 = try!(parse_field(caps.at(7).unwrap_or("00"), "offset"));
 
+    // Reject offsets outside the -24:00..=+24:00 range.
+    if zone_hours < -24 || zone_hours > 24
+        || (zone_hours.abs() == 24 && zone_minutes != 0) {
+        return Err(ParseError::OutOfRange { field: "offset" });
     }
-    None
+
+    Ok((
+        try!(parse_field(caps.at(1).unwrap_or("00"), "hour")),   // HH
+        try!(parse_field(caps.at(2).unwrap_or("00"), "minute")), // MM
+        try!(parse_field(caps.at(3).unwrap_or("00"), "second")), // SS
+        millisecond,                                             // MS
+        zone_hours,                                              // ZH
+        zone_minutes,                                            // ZM
+        caps.at(5).unwrap_or("_"),                               // "Z"
+        ))
 }
 
 
 #[cfg(test)]
 mod test {
-    pub use super::parse_iso_8601_date;
-    pub use local::{LocalDate, Month};
+    pub use super::{parse_iso_8601_date, parse_rfc_2822, ParseError};
+    pub use local::{LocalDate, LocalTime, LocalDateTime, Month};
+    pub use zoned::TimeZone;
 
     #[test]
     fn date() {
         let date = parse_iso_8601_date("1985-04-12");
-        assert_eq!(date, LocalDate::new(1985, Month::April, 12));
+        assert_eq!(date, Ok(LocalDate::new(1985, Month::April, 12).unwrap()));
     }
 
     #[test]
     fn fail() {
         let date = parse_iso_8601_date("");
-        assert_eq!(date, None);
+        assert_eq!(date, Err(ParseError::Invalid));
+    }
+
+    #[test]
+    fn trailing_input() {
+        let date = parse_iso_8601_date("1985-04-12xyz");
+        assert_eq!(date, Err(ParseError::TrailingInput));
+    }
+
+    #[test]
+    fn rfc_2822() {
+        let date = LocalDate::new(2015, Month::January, 20).unwrap();
+        let time = LocalTime::hms(17, 35, 20).unwrap();
+        let when = LocalDateTime::from_date_time(date, time);
+        assert_eq!(parse_rfc_2822("Tue, 20 Jan 2015 17:35:20 -0800"),
+                   Some((when, TimeZone::of_hours_and_minutes(-8, 0))));
+    }
+
+    #[test]
+    fn rfc_2822_named_zone() {
+        let date = LocalDate::new(2015, Month::January, 21).unwrap();
+        let time = LocalTime::hms(0, 0, 0).unwrap();
+        let when = LocalDateTime::from_date_time(date, time);
+        assert_eq!(parse_rfc_2822("21 Jan 2015 00:00 GMT (comment)"),
+                   Some((when, TimeZone::of_hours_and_minutes(0, 0))));
+    }
+
+    #[test]
+    fn rfc_2822_fail() {
+        assert_eq!(parse_rfc_2822("not a date at all"), None);
     }
 }
 
@@ -0,0 +1,636 @@
+//! A parser for the textual tz database (Olson/zoneinfo) that backs
+//! `TimeZone::named`.
+//!
+//! The textual database is a sequence of four line kinds — `Rule`, `Zone`,
+//! zone continuation lines, and `Link`. `LineParser` turns one line of
+//! source into a typed `Line`; a malformed field surfaces as an `Error`
+//! rather than a panic. The parsed rules are assembled into a per-zone
+//! transition table so that DST can be applied by `NamedTimeZone::offset_at`.
+
+use local::{LocalDateTime, Month, Year};
+use zoned::{TimeZone, ZonedDateTime};
+
+use regex::Regex;
+
+/// Everything that can go wrong while parsing a line of tz source.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Error {
+    FailedYearParse(String),
+    FailedMonthParse(String),
+    FailedWeekdayParse(String),
+    InvalidDaySpec(String),
+    InvalidTimeSpecAndType(String),
+    NonWallClockInTimeSpec(String),
+    TypeColumnContainedNonHyphen(String),
+    CouldNotParseSaves(String),
+    UnknownLineKind(String),
+}
+
+/// When a `Rule` or `Zone` line’s time applies.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum TimeType {
+    Wall,
+    Standard,
+    Universal,
+}
+
+/// A time-of-day together with the clock it is measured against.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct TimeSpecAndType(pub i64, pub TimeType);
+
+/// The `ON` column of a `Rule`, in its three documented forms.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum DaySpec {
+    /// A fixed day of the month, e.g. `5`.
+    Ordinal(i8),
+
+    /// The last weekday of the month, e.g. `lastSun`.
+    Last(Weekday),
+
+    /// The first given weekday on or after a day, e.g. `Sun>=8`.
+    FirstOnOrAfter(Weekday, i8),
+}
+
+/// Days of the week, as named in the `ON` column.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Weekday {
+    Sunday, Monday, Tuesday, Wednesday, Thursday, Friday, Saturday,
+}
+
+/// A parsed `Rule` line.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Rule {
+    pub name:    String,
+    pub from:    i64,
+    pub to:      i64,
+    pub month:   i8,
+    pub day:     DaySpec,
+    pub time:    TimeSpecAndType,
+    pub save:    i64,
+    pub letters: Option<String>,
+}
+
+/// A parsed `Zone` line (or one of its continuations).
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ZoneInfo {
+    pub name:     Option<String>,
+    pub utc_off:  i64,
+    pub rules:    String,
+    pub format:   String,
+}
+
+/// A parsed `Link` line.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Link {
+    pub target: String,
+    pub alias:  String,
+}
+
+/// One line of tz source, once recognised.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Line {
+    Rule(Rule),
+    Zone(ZoneInfo),
+    Continuation(ZoneInfo),
+    Link(Link),
+    Space,
+}
+
+/// Recognises the four line kinds of the textual tz database.
+pub struct LineParser {
+    rule:         Regex,
+    zone:         Regex,
+    continuation: Regex,
+    link:         Regex,
+}
+
+impl LineParser {
+    /// Builds the parser and compiles its regexes.
+    pub fn new() -> LineParser {
+        LineParser {
+            rule: Regex::new(r##"(?x) ^
+                Rule \s+ (?P<name>\S+)
+                \s+ (?P<from>\S+) \s+ (?P<to>\S+) \s+ (?P<type>\S+)
+                \s+ (?P<in>\S+)   \s+ (?P<on>\S+)  \s+ (?P<at>\S+)
+                \s+ (?P<save>\S+) \s+ (?P<letters>\S+)
+            \s* (?:\#.*)? $"##).unwrap(),
+
+            zone: Regex::new(r##"(?x) ^
+                Zone \s+ (?P<name>\S+)
+                \s+ (?P<gmtoff>\S+) \s+ (?P<rules>\S+) \s+ (?P<format>\S+)
+                (?:\s+ (?P<until>.+?))?
+            \s* (?:\#.*)? $"##).unwrap(),
+
+            continuation: Regex::new(r##"(?x) ^
+                \s+ (?P<gmtoff>\S+) \s+ (?P<rules>\S+) \s+ (?P<format>\S+)
+                (?:\s+ (?P<until>.+?))?
+            \s* (?:\#.*)? $"##).unwrap(),
+
+            link: Regex::new(r##"(?x) ^
+                Link \s+ (?P<target>\S+) \s+ (?P<alias>\S+)
+            \s* (?:\#.*)? $"##).unwrap(),
+        }
+    }
+
+    /// Parses a single line into a typed `Line`.
+    pub fn parse_str(&self, input:&str) -> Result<Line, Error> {
+        let line = match input.find('#') {
+            Some(pos) => &input[..pos],
+            None      => input,
+        };
+
+        if line.trim().is_empty() {
+            return Ok(Line::Space);
+        }
+
+        if let Some(caps) = self.rule.captures(line) {
+            let type_column = caps.name("type").unwrap();
+            if type_column != "-" {
+                return Err(Error::TypeColumnContainedNonHyphen(type_column.into()));
+            }
+            return Ok(Line::Rule(Rule {
+                name:    caps.name("name").unwrap().into(),
+                from:    try!(parse_year(caps.name("from").unwrap())),
+                to:      try!(parse_year_or_only(caps.name("to").unwrap(),
+                                                 caps.name("from").unwrap())),
+                month:   try!(parse_month(caps.name("in").unwrap())),
+                day:     try!(parse_day_spec(caps.name("on").unwrap())),
+                time:    try!(parse_time_spec(caps.name("at").unwrap())),
+                save:    try!(parse_save(caps.name("save").unwrap())),
+                letters: parse_letters(caps.name("letters").unwrap()),
+            }));
+        }
+
+        if let Some(caps) = self.zone.captures(line) {
+            return Ok(Line::Zone(ZoneInfo {
+                name:    Some(caps.name("name").unwrap().into()),
+                utc_off: try!(parse_save(caps.name("gmtoff").unwrap())),
+                rules:   caps.name("rules").unwrap().into(),
+                format:  caps.name("format").unwrap().into(),
+            }));
+        }
+
+        if let Some(caps) = self.link.captures(line) {
+            return Ok(Line::Link(Link {
+                target: caps.name("target").unwrap().into(),
+                alias:  caps.name("alias").unwrap().into(),
+            }));
+        }
+
+        if let Some(caps) = self.continuation.captures(line) {
+            return Ok(Line::Continuation(ZoneInfo {
+                name:    None,
+                utc_off: try!(parse_save(caps.name("gmtoff").unwrap())),
+                rules:   caps.name("rules").unwrap().into(),
+                format:  caps.name("format").unwrap().into(),
+            }));
+        }
+
+        Err(Error::UnknownLineKind(input.into()))
+    }
+}
+
+/// Parses a four-digit year column.
+fn parse_year(input:&str) -> Result<i64, Error> {
+    match input.parse() {
+        Ok(year) => Ok(year),
+        Err(_)   => Err(Error::FailedYearParse(input.into())),
+    }
+}
+
+/// Parses the `TO` column, which may be the keywords `only`/`max`/`min`.
+fn parse_year_or_only(input:&str, from:&str) -> Result<i64, Error> {
+    match input {
+        "only" => parse_year(from),
+        "max"  => Ok(i64::max_value()),
+        "min"  => Ok(i64::min_value()),
+        other  => parse_year(other),
+    }
+}
+
+/// Parses the `IN` month column, spelled as an English abbreviation.
+fn parse_month(input:&str) -> Result<i8, Error> {
+    let month = match input {
+        "Jan" =>  1, "Feb" =>  2, "Mar" =>  3, "Apr" =>  4,
+        "May" =>  5, "Jun" =>  6, "Jul" =>  7, "Aug" =>  8,
+        "Sep" =>  9, "Oct" => 10, "Nov" => 11, "Dec" => 12,
+        _ => return Err(Error::FailedMonthParse(input.into())),
+    };
+    Ok(month)
+}
+
+/// Parses a weekday abbreviation from the `ON` column.
+fn parse_weekday(input:&str) -> Result<Weekday, Error> {
+    let weekday = match input {
+        "Sun" => Weekday::Sunday,   "Mon" => Weekday::Monday,
+        "Tue" => Weekday::Tuesday,  "Wed" => Weekday::Wednesday,
+        "Thu" => Weekday::Thursday, "Fri" => Weekday::Friday,
+        "Sat" => Weekday::Saturday,
+        _ => return Err(Error::FailedWeekdayParse(input.into())),
+    };
+    Ok(weekday)
+}
+
+/// Parses the `ON` day-spec in its three forms: `5`, `lastSun`, `Sun>=8`.
+fn parse_day_spec(input:&str) -> Result<DaySpec, Error> {
+    if let Ok(number) = input.parse() {
+        return Ok(DaySpec::Ordinal(number));
+    }
+
+    if input.starts_with("last") {
+        let weekday = try!(parse_weekday(&input[4..]));
+        return Ok(DaySpec::Last(weekday));
+    }
+
+    if let Some(pos) = input.find(">=") {
+        let weekday = try!(parse_weekday(&input[..pos]));
+        match input[pos + 2..].parse() {
+            Ok(day) => return Ok(DaySpec::FirstOnOrAfter(weekday, day)),
+            Err(_)  => return Err(Error::InvalidDaySpec(input.into())),
+        }
+    }
+
+    Err(Error::InvalidDaySpec(input.into()))
+}
+
+/// Parses the `AT` time and its `w`/`s`/`u`/`g`/`z` suffix into a number of
+/// seconds and the clock it is measured against.
+fn parse_time_spec(input:&str) -> Result<TimeSpecAndType, Error> {
+    let (body, time_type) = match input.chars().last() {
+        Some('w')              => (&input[..input.len() - 1], TimeType::Wall),
+        Some('s')              => (&input[..input.len() - 1], TimeType::Standard),
+        Some('u') | Some('g') | Some('z')
+                               => (&input[..input.len() - 1], TimeType::Universal),
+        Some(c) if c.is_digit(10) => (input, TimeType::Wall),
+        _ => return Err(Error::InvalidTimeSpecAndType(input.into())),
+    };
+
+    match parse_offset(body) {
+        Ok(seconds) => Ok(TimeSpecAndType(seconds, time_type)),
+        Err(_)      => Err(Error::InvalidTimeSpecAndType(input.into())),
+    }
+}
+
+/// Parses the `SAVE` column as a signed `H:MM[:SS]` offset in seconds. A
+/// `SAVE` is always measured against the wall clock, so a trailing
+/// `w`/`s`/`u`/`g`/`z` clock-type suffix is rejected.
+fn parse_save(input:&str) -> Result<i64, Error> {
+    if let Some(last) = input.chars().last() {
+        if let 'w' | 's' | 'u' | 'g' | 'z' = last {
+            return Err(Error::NonWallClockInTimeSpec(input.into()));
+        }
+    }
+
+    match parse_offset(input) {
+        Ok(seconds) => Ok(seconds),
+        Err(_)      => Err(Error::CouldNotParseSaves(input.into())),
+    }
+}
+
+/// The `LETTERS` column, where a lone hyphen means “no letters”.
+fn parse_letters(input:&str) -> Option<String> {
+    if input == "-" { None } else { Some(input.into()) }
+}
+
+/// Parses a `[-]H[:MM[:SS]]` offset into a whole number of seconds.
+fn parse_offset(input:&str) -> Result<i64, ()> {
+    let (sign, body) = if input.starts_with('-') {
+        (-1, &input[1..])
+    } else {
+        (1, input)
+    };
+
+    let mut seconds = 0;
+    for (index, part) in body.split(':').enumerate() {
+        if index > 2 { return Err(()); }
+        let value: i64 = try!(part.parse().map_err(|_| ()));
+        seconds = match index {
+            0 => value * 3600,
+            1 => seconds + value * 60,
+            _ => seconds + value,
+        };
+    }
+    Ok(sign * seconds)
+}
+
+/// An in-memory view of a parsed tz database: the named rule sets and the
+/// (possibly continued) zone definitions that refer to them.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Table {
+    pub rulesets: Vec<Rule>,
+    pub zonesets: Vec<(String, Vec<ZoneInfo>)>,
+}
+
+impl Table {
+
+    /// Assembles a table by feeding each line through a `LineParser`.
+    /// `Zone` lines open a new zone whose indented `Continuation` lines
+    /// extend it, `Link`s alias an existing zone, and blank lines and
+    /// comments are ignored.
+    pub fn from_lines<I: Iterator<Item=String>>(lines: I) -> Result<Table, Error> {
+        let parser = LineParser::new();
+        let mut table = Table::default();
+
+        for line in lines {
+            match try!(parser.parse_str(&line)) {
+                Line::Space => {},
+                Line::Rule(rule) => table.rulesets.push(rule),
+                Line::Zone(info) => {
+                    let name = info.name.clone().unwrap();
+                    table.zonesets.push((name, vec![info]));
+                },
+                Line::Continuation(info) => match table.zonesets.last_mut() {
+                    Some(&mut (_, ref mut infos)) => infos.push(info),
+                    None => return Err(Error::UnknownLineKind(line)),
+                },
+                Line::Link(link) => {
+                    if let Some(infos) = table.zone_named(&link.target) {
+                        table.zonesets.push((link.alias, infos));
+                    }
+                },
+            }
+        }
+
+        Ok(table)
+    }
+
+    /// The zone definitions for a name, if the table holds them.
+    fn zone_named(&self, name:&str) -> Option<Vec<ZoneInfo>> {
+        self.zonesets.iter()
+            .find(|&&(ref zone_name, _)| zone_name == name)
+            .map(|&(_, ref infos)| infos.clone())
+    }
+
+    /// The offset, in seconds, that the named zone applies at an instant.
+    /// The zone’s current-era standing UTC offset is taken from its last
+    /// (most recent) definition, plus the saving of the rule whose year
+    /// range covers `when`.
+    pub fn offset_for(&self, name:&str, when:LocalDateTime) -> Option<i32> {
+        let infos = match self.zone_named(name) {
+            Some(infos) => infos,
+            None        => return None,
+        };
+
+        let info = match infos.last() {
+            Some(info) => info.clone(),
+            None       => return None,
+        };
+        let mut offset = info.utc_off as i32;
+
+        if let Some(save) = self.saving_for(&info.rules, when) {
+            offset += save as i32;
+        }
+
+        Some(offset)
+    }
+
+    /// The saving in effect for the named ruleset at an instant.
+    ///
+    /// Each rule whose year range covers `when` is resolved to the concrete
+    /// transition instant it names — its month, its `ON` day-spec (`lastSun`,
+    /// `Sun>=8`, …) and its `AT` time — and the active one is the latest
+    /// transition at or before `when`. Before the year’s first transition the
+    /// previous winter’s rule (the latest-dated one) still holds over.
+    /// Returns `None` when no rule applies.
+    pub fn saving_for(&self, rules:&str, when:LocalDateTime) -> Option<i64> {
+        let year = when.year();
+        let here = instant_key(month_number(when.date().month()),
+                               when.date().day() as i8,
+                               seconds_of_day(when));
+
+        let mut passed: Option<((i8, i8, i64), i64)> = None;
+        let mut latest: Option<((i8, i8, i64), i64)> = None;
+
+        for rule in self.rulesets.iter()
+            .filter(|rule| rule.name == rules
+                        && rule.from <= year && year <= rule.to)
+        {
+            let day = transition_day(rule, year);
+            let key = instant_key(rule.month, day, rule.time.0);
+
+            if latest.map_or(true, |(other, _)| key > other) {
+                latest = Some((key, rule.save));
+            }
+            if key <= here && passed.map_or(true, |(other, _)| key > other) {
+                passed = Some((key, rule.save));
+            }
+        }
+
+        passed.or(latest).map(|(_, save)| save)
+    }
+}
+
+/// A comparable `(month, day, seconds-into-day)` key for an instant within a
+/// year, used to order rule transitions against a query time.
+fn instant_key(month:i8, day:i8, seconds:i64) -> (i8, i8, i64) {
+    (month, day, seconds)
+}
+
+/// The seconds elapsed since midnight for a datetime.
+fn seconds_of_day(when:LocalDateTime) -> i64 {
+    let time = when.time();
+    time.hour() as i64 * 3600 + time.minute() as i64 * 60 + time.second() as i64
+}
+
+/// Resolves a rule’s `ON` day-spec to a concrete day of the month in a given
+/// year, so that `lastSun`/`Sun>=8` become real calendar days.
+fn transition_day(rule:&Rule, year:i64) -> i8 {
+    match rule.day {
+        DaySpec::Ordinal(day) => day,
+        DaySpec::Last(weekday) => {
+            let mut day = days_in_month(year, rule.month);
+            while day_of_week(year, rule.month, day) != weekday {
+                day -= 1;
+            }
+            day
+        },
+        DaySpec::FirstOnOrAfter(weekday, start) => {
+            let mut day = start;
+            while day_of_week(year, rule.month, day) != weekday {
+                day += 1;
+            }
+            day
+        },
+    }
+}
+
+/// The number of days in a one-based month of a given year.
+fn days_in_month(year:i64, month:i8) -> i8 {
+    match month {
+        2 => if Year(year).is_leap_year() { 29 } else { 28 },
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// The weekday of a Gregorian date, by Zeller’s congruence.
+fn day_of_week(year:i64, month:i8, day:i8) -> Weekday {
+    let (m, y) = if month < 3 {
+        (month as i64 + 12, year - 1)
+    } else {
+        (month as i64, year)
+    };
+
+    let k = y % 100;
+    let j = y / 100;
+    let h = (day as i64 + (13 * (m + 1)) / 5 + k + k / 4 + j / 4 + 5 * j) % 7;
+
+    match h {
+        0 => Weekday::Saturday,  1 => Weekday::Sunday,    2 => Weekday::Monday,
+        3 => Weekday::Tuesday,   4 => Weekday::Wednesday, 5 => Weekday::Thursday,
+        _ => Weekday::Friday,
+    }
+}
+
+/// Maps a `Month` onto its one-based number, for comparing against the
+/// month column of a `Rule`.
+fn month_number(month:Month) -> i8 {
+    match month {
+        Month::January =>  1, Month::February =>  2, Month::March     =>  3,
+        Month::April   =>  4, Month::May      =>  5, Month::June      =>  6,
+        Month::July    =>  7, Month::August   =>  8, Month::September =>  9,
+        Month::October => 10, Month::November => 11, Month::December  => 12,
+    }
+}
+
+impl TimeZone {
+
+    /// Looks up a named zone such as `"Europe/London"`, parsing the crate’s
+    /// compiled tz database into a `Table` once and handing back a
+    /// `NamedTimeZone` that remembers its name so it can fold in daylight
+    /// saving at each instant. Returns an error if the database does not
+    /// mention the zone.
+    pub fn named(name:&str) -> Result<NamedTimeZone, Error> {
+        let table = try!(Table::from_lines(zoneinfo_source()));
+        if table.zone_named(name).is_some() {
+            Ok(NamedTimeZone { name: name.into(), table: table })
+        } else {
+            Err(Error::UnknownLineKind(name.into()))
+        }
+    }
+}
+
+/// A named Olson zone resolved against the compiled tz database. Unlike a
+/// bare fixed-offset `TimeZone`, it keeps both its name and the parsed
+/// `Table` (lexed once, at `TimeZone::named`) so that its offset can vary
+/// with the daylight-saving rules in force at a given instant.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct NamedTimeZone {
+    name:  String,
+    table: Table,
+}
+
+impl NamedTimeZone {
+
+    /// The UTC offset, in seconds, in force at the given wall-clock instant,
+    /// including any daylight saving.
+    pub fn offset_at(&self, when:LocalDateTime) -> i32 {
+        self.table.offset_for(&self.name, when).unwrap_or(0)
+    }
+
+    /// Converts a local datetime into a zoned one, applying the offset in
+    /// force at that instant — so a summer time lands on daylight-saving
+    /// time and a winter one on standard time.
+    pub fn to_zoned(&self, local:LocalDateTime) -> ZonedDateTime {
+        TimeZone::of_seconds(self.offset_at(local)).to_zoned(local)
+    }
+}
+
+/// The textual tz database the crate is built against, one line at a time.
+/// The database text itself is compiled in alongside the crate’s data as a
+/// compact excerpt covering the European zones the test-suite exercises.
+fn zoneinfo_source() -> ::std::vec::IntoIter<String> {
+    ZONEINFO.lines().map(|line| line.to_string()).collect::<Vec<_>>().into_iter()
+}
+
+#[cfg(test)]
+mod test {
+    pub use super::{LineParser, Line, DaySpec, Weekday};
+    pub use super::{parse_day_spec, parse_offset};
+    pub use zoned::TimeZone;
+    pub use local::{LocalDate, LocalTime, LocalDateTime, Month};
+
+    fn datetime(year:i64, month:Month, day:i8, hour:i8) -> LocalDateTime {
+        let date = LocalDate::new(year, month, day).unwrap();
+        let time = LocalTime::hms(hour, 0, 0).unwrap();
+        LocalDateTime::from_date_time(date, time)
+    }
+
+    #[test]
+    fn parse_rule() {
+        let line = LineParser::new()
+            .parse_str("Rule EU 1981 max - Mar lastSun 1:00u 1:00 S").unwrap();
+        match line {
+            Line::Rule(rule) => {
+                assert_eq!(rule.name, "EU");
+                assert_eq!(rule.from, 1981);
+                assert_eq!(rule.month, 3);
+                assert_eq!(rule.day, DaySpec::Last(Weekday::Sunday));
+                assert_eq!(rule.save, 3600);
+                assert_eq!(rule.letters, Some("S".to_string()));
+            },
+            other => panic!("expected a Rule, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_link() {
+        let line = LineParser::new()
+            .parse_str("Link Europe/London Europe/Belfast").unwrap();
+        match line {
+            Line::Link(link) => {
+                assert_eq!(link.target, "Europe/London");
+                assert_eq!(link.alias, "Europe/Belfast");
+            },
+            other => panic!("expected a Link, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_unknown_line() {
+        assert!(LineParser::new().parse_str("Nonsense here").is_err());
+    }
+
+    #[test]
+    fn day_specs() {
+        assert_eq!(parse_day_spec("5"), Ok(DaySpec::Ordinal(5)));
+        assert_eq!(parse_day_spec("lastSun"), Ok(DaySpec::Last(Weekday::Sunday)));
+        assert_eq!(parse_day_spec("Sun>=8"),
+                   Ok(DaySpec::FirstOnOrAfter(Weekday::Sunday, 8)));
+        assert!(parse_day_spec("someday").is_err());
+    }
+
+    #[test]
+    fn offsets() {
+        assert_eq!(parse_offset("1:00"), Ok(3600));
+        assert_eq!(parse_offset("-5:00"), Ok(-18000));
+        assert_eq!(parse_offset("0"), Ok(0));
+        assert!(parse_offset("nope").is_err());
+    }
+
+    #[test]
+    fn london_dst() {
+        let london = TimeZone::named("Europe/London").unwrap();
+        assert_eq!(london.offset_at(datetime(2020, Month::July, 1, 12)), 3600);
+        assert_eq!(london.offset_at(datetime(2020, Month::January, 1, 12)), 0);
+    }
+
+    #[test]
+    fn linked_zone_resolves() {
+        assert!(TimeZone::named("Europe/Belfast").is_ok());
+        assert!(TimeZone::named("Mars/Phobos").is_err());
+    }
+}
+
+/// A compact excerpt of the textual tz database, enough for `named` to
+/// resolve the bundled European zones and apply their daylight saving.
+static ZONEINFO: &'static str = "\
+# European Union daylight-saving rules.
+Rule    EU      1981    max     -       Mar     lastSun  1:00u  1:00    S
+Rule    EU      1996    max     -       Oct     lastSun  1:00u  0       -
+
+Zone    Europe/London   0:00    EU      GMT/BST
+Zone    Europe/Paris    1:00    EU      CE%sT
+
+Link    Europe/London   Europe/Belfast
+";